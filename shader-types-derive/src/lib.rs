@@ -0,0 +1,174 @@
+//! The `#[derive(Std140)]` proc-macro backing the `derive` feature of `shader-types`.
+//!
+//! This crate is not meant to be used directly; depend on `shader-types` with the `derive`
+//! feature enabled instead.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Type};
+
+/// Derives a std140-padded mirror struct.
+///
+/// Given a struct made up of `shader-types` vector, matrix, and array types, this emits a
+/// sibling struct named `<Name>Std140` with `_padN: [u8; N]` fields inserted before each member
+/// (and a `_pad_tail: [u8; N]` after the last one, rounding the size up to a multiple of 16) so
+/// the whole thing is laid out exactly like the equivalent GLSL uniform block, without the
+/// padding needing to be tracked by hand.
+///
+/// The padding amounts aren't computed here in the macro: each generated field type's
+/// [`Std140Element`](::shader_types::Std140Element) alignment and size are referenced by name in
+/// the expansion, and the compiler's own const evaluation works out the padding once the real
+/// types are known. This only works for fields whose type implements `Std140Element`.
+#[proc_macro_derive(Std140)]
+pub fn derive_std140(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "Std140 can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "Std140 can only be derived for structs",
+            ))
+        }
+    };
+
+    let name = &input.ident;
+    let mirror_name = format_ident!("{}Std140", name);
+
+    // `offset_n` is the raw (unaligned) byte offset at which the first `n` fields end, with
+    // `offset_0` = 0. Each step emits a const computing the *next* one: the previous end rounded
+    // up to this field's `Std140Element::ALIGNMENT`, plus its `SIZE`; the gap between the previous
+    // end and that aligned start becomes the `_padN` field inserted *before* this field. Real
+    // alignment/size values aren't known until the compiler
+    // resolves `Std140Element` for the real field types, so all of this is plain const arithmetic
+    // for the compiler to evaluate, not something computed here at macro-expansion time.
+    let mut offset_consts = Vec::with_capacity(fields.len() + 1);
+    let mut mirror_fields = Vec::with_capacity(fields.len() * 2);
+
+    // Pieces fed to `concatcp!` to build `GLSL_BLOCK` below. Each field's `GLSL_TYPE` is an
+    // associated const of its real (possibly generic) type, so, like the offsets above, the
+    // actual string isn't known until the compiler resolves it post-expansion; `concatcp!`
+    // evaluates the whole concatenation at compile time once it is.
+    let mut glsl_pieces = vec![quote! { "struct ", stringify!(#name), " {\n" }];
+
+    let offset_ident = |index: usize| format_ident!("__{}_OFFSET_{}", mirror_name, index);
+
+    let mut prev_offset = offset_ident(0);
+    offset_consts.push(quote! {
+        #[allow(non_upper_case_globals)]
+        const #prev_offset: usize = 0;
+    });
+
+    for (index, field) in fields.iter().enumerate() {
+        let field_name = field.ident.as_ref().expect("named field");
+        let field_ty = &field.ty;
+
+        let pad_ident = format_ident!("_pad{}", index);
+        let next_offset = offset_ident(index + 1);
+
+        // `prev_offset` is the raw (possibly unaligned) end of the previous field; round it up
+        // to this field's own alignment to get where it actually starts, then the padding before
+        // it is just the gap between the two.
+        mirror_fields.push(quote! {
+            #[doc(hidden)]
+            #pad_ident: [u8; {
+                let align = <#field_ty as ::shader_types::Std140Element>::ALIGNMENT;
+                (#prev_offset + align - 1) / align * align - #prev_offset
+            }],
+        });
+        mirror_fields.push(quote! {
+            pub #field_name: #field_ty,
+        });
+
+        glsl_pieces.push(glsl_field_fragment(field_ty, field_name));
+
+        offset_consts.push(quote! {
+            #[allow(non_upper_case_globals)]
+            const #next_offset: usize = {
+                let align = <#field_ty as ::shader_types::Std140Element>::ALIGNMENT;
+                let start = (#prev_offset + align - 1) / align * align;
+                start + <#field_ty as ::shader_types::Std140Element>::SIZE
+            };
+        });
+
+        prev_offset = next_offset;
+    }
+
+    let end_offset = prev_offset;
+    let size_ident = format_ident!("__{}_SIZE", mirror_name);
+    offset_consts.push(quote! {
+        #[allow(non_upper_case_globals)]
+        const #size_ident: usize = (#end_offset + 15) / 16 * 16;
+    });
+
+    mirror_fields.push(quote! {
+        #[doc(hidden)]
+        _pad_tail: [u8; #size_ident - #end_offset],
+    });
+
+    let bytemuck_impls = quote! {
+        #[cfg(feature = "bytemuck")]
+        unsafe impl ::bytemuck::Zeroable for #mirror_name {}
+        #[cfg(feature = "bytemuck")]
+        unsafe impl ::bytemuck::Pod for #mirror_name {}
+    };
+
+    glsl_pieces.push(quote! { "};\n" });
+
+    Ok(quote! {
+        #(#offset_consts)*
+
+        #[repr(C)]
+        #[derive(Debug, Copy, Clone)]
+        #[allow(non_snake_case)]
+        pub struct #mirror_name {
+            #(#mirror_fields)*
+        }
+
+        #bytemuck_impls
+
+        impl #name {
+            /// The GLSL `struct { ... }` declaration this Rust struct corresponds to, assembled
+            /// from each field's [`GlslType`](::shader_types::GlslType). Diff this against your
+            /// actual shader source (or assert on it in a test) to catch layout drift between the
+            /// two without a full reflection system.
+            pub const GLSL_BLOCK: &'static str = ::shader_types::const_format::concatcp!(#(#glsl_pieces),*);
+        }
+    })
+}
+
+/// Builds the `"    <glsl type> <field name>[<len>];\n"` (or without the `[<len>]` for a
+/// non-array field) piece of `GLSL_BLOCK` for one field.
+fn glsl_field_fragment(field_ty: &Type, field_name: &syn::Ident) -> TokenStream2 {
+    match field_ty {
+        Type::Array(array) => {
+            let elem = &array.elem;
+            let len = &array.len;
+            quote! {
+                "    ", <#elem as ::shader_types::GlslType>::GLSL_TYPE, " ", stringify!(#field_name), "[", stringify!(#len), "];\n"
+            }
+        }
+        _ => quote! {
+            "    ", <#field_ty as ::shader_types::GlslType>::GLSL_TYPE, " ", stringify!(#field_name), ";\n"
+        },
+    }
+}
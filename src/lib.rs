@@ -6,6 +6,10 @@
 //! This fixes the padding within members of structs but padding between members needs to be minded.
 //! The types in [`padding`] are there to make this easier.
 //!
+//! All of the above is std140, the layout used by uniform buffers. Storage buffers and push
+//! constants use std430 instead, which does not round array strides and struct alignment up to
+//! 16 bytes. The [`std430`] module provides matrix and array types laid out accordingly.
+//!
 //! Vectors are constructable to/from an array of their underlying type. Matrices are constructable
 //! to/from both 1d and 2d arrays as well as an array of the underlying _vector_ type. (eg. [`Mat2`] can be
 //! constructed from `[Vec2; 2]`)
@@ -64,10 +68,17 @@
 //!
 //! # MSRV
 //!
-//! Rust 1.34
+//! Rust 1.63, for `core::array::from_fn` (used by the [`Vector`]/[`Matrix`] `Default` impls;
+//! the const generics they're built on only need 1.51).
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+// The `Std140` derive expands to absolute `::shader_types::...` paths so it works the same way
+// whether it's used from a downstream crate or (as in `std140_derive_tests` below) from this
+// crate itself; this alias is what makes the latter resolve, the same trick `syn` uses.
+#[cfg(feature = "derive")]
+extern crate self as shader_types;
+
 macro_rules! define_vectors {
     ( $(( $name:ident, $mint_name:ident, $prim:ident * $count:literal, align: $align:literal, size: $size:literal ),)* ) => {
         $(
@@ -93,6 +104,11 @@ macro_rules! define_vectors {
         #[doc = $doc]
         #[repr(C, align($align))]
         #[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd)]
+        // `transparent` makes this serialize/deserialize as a bare `[inner; count]` array rather
+        // than `{"inner": [...]}`, matching the matrix types' hand-written impls below (which
+        // serialize `inner` directly to skip their private `_padding` field).
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(transparent))]
         pub struct $name {
             pub inner: [$ty; $count],
         }
@@ -152,9 +168,77 @@ macro_rules! define_vectors {
                 other.inner
             }
         }
+
+        impl From<$name> for Vector<$ty, $count> {
+            #[inline(always)]
+            fn from(other: $name) -> Self {
+                Self { inner: other.inner }
+            }
+        }
+
+        impl From<Vector<$ty, $count>> for $name {
+            #[inline(always)]
+            fn from(other: Vector<$ty, $count>) -> Self {
+                Self { inner: other.inner }
+            }
+        }
     };
 }
 
+/// Vector of `N` `T` values, generic over element type and length.
+///
+/// **Do not put this directly in a `#[repr(C)]` uniform/storage struct.** Stable Rust cannot
+/// derive a `#[repr(align(N))]` from a const generic parameter, so this type has no alignment of
+/// its own beyond `T`'s: it's a plain `#[repr(C)]` wrapper around `[T; N]`, not a drop-in
+/// replacement for the std140-aligned [`Vec2`]/[`Vec3`]/[`Vec4`] (and the double/unsigned/signed
+/// variants), which keep their own hand-written, correctly aligned definitions above. Swapping one
+/// of those types for `Vector<T, N>` in a uniform-block struct compiles fine and silently
+/// produces the wrong GPU-facing layout. Those concrete types convert to and from `Vector<T, N>`
+/// via `From`/`Into`, so generic code can abstract over vector width without giving up the
+/// std140 alignment guarantee at the edges where it matters — use `Vector<T, N>` there, not in
+/// the struct itself.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Vector<T, const N: usize> {
+    pub inner: [T; N],
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable, const N: usize> bytemuck::Zeroable for Vector<T, N> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod, const N: usize> bytemuck::Pod for Vector<T, N> {}
+
+impl<T, const N: usize> Vector<T, N> {
+    #[inline(always)]
+    pub fn new(inner: [T; N]) -> Self {
+        Self { inner }
+    }
+}
+
+// `#[derive(Default)]` doesn't work here: std only implements `Default` for `[T; N]` up to a
+// fixed set of small N, not generically over the const parameter. Fill each element by hand
+// instead.
+impl<T: Default, const N: usize> Default for Vector<T, N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(core::array::from_fn(|_| T::default()))
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Vector<T, N> {
+    #[inline(always)]
+    fn from(inner: [T; N]) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T, const N: usize> From<Vector<T, N>> for [T; N] {
+    #[inline(always)]
+    fn from(other: Vector<T, N>) -> Self {
+        other.inner
+    }
+}
+
 define_vectors! {
     (Vec2, Vector2, f32 * 2, align: 8, size: 16),
     (Vec3, Vector3, f32 * 3, align: 16, size: 24),
@@ -209,6 +293,24 @@ macro_rules! define_matrices {
         #[cfg(feature = "bytemuck")]
         unsafe impl bytemuck::Pod for $name {}
 
+        // `_padding` is a private implementation detail, so `#[derive(Serialize, Deserialize)]`
+        // isn't an option here: it would serialize the padding bytes too. Serialize/deserialize
+        // just `inner` instead, reconstructing the padding as zero on the way back in.
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.inner.serialize(serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let inner = <[$ty; $count_y]>::deserialize(deserializer)?;
+                Ok(Self { inner, _padding: [0; $padding] })
+            }
+        }
+
         impl $name {
             #[inline(always)]
             pub fn new(inner: [$ty; $count_y]) -> Self {
@@ -291,9 +393,76 @@ macro_rules! define_matrices {
                 [$(<[$inner_ty; $count_x]>::from(other.inner[$idx])),*]
             }
         }
+
+        impl From<$name> for Matrix<$inner_ty, $count_y, $count_x> {
+            #[inline(always)]
+            fn from(other: $name) -> Self {
+                Self { inner: [$(Vector::from(other.inner[$idx])),*] }
+            }
+        }
+
+        impl From<Matrix<$inner_ty, $count_y, $count_x>> for $name {
+            #[inline(always)]
+            fn from(other: Matrix<$inner_ty, $count_y, $count_x>) -> Self {
+                Self { inner: [$(<$ty>::from(other.inner[$idx])),*], _padding: [0; $padding] }
+            }
+        }
     };
 }
 
+/// Matrix of `T` values with `C` columns of `R` rows each, generic over element type and
+/// dimensions.
+///
+/// **Do not put this directly in a `#[repr(C)]` uniform/storage struct** — same caveat as
+/// [`Vector`]. Stored as `C` columns of [`Vector<T, R>`](Vector), matching how
+/// [`Mat2`]/[`Mat3`]/[`Mat4`] (and the double variants) lay themselves out. As with `Vector`,
+/// stable Rust can't derive the std140 struct alignment (which depends on `R`, and is rounded up
+/// further when matrix alignment is folded into the members-need-minding padding rules) from
+/// `C`/`R` as const generics, so this type carries no alignment or inter-column padding of its
+/// own, and swapping one of the named aliases for it in a uniform block silently produces the
+/// wrong GPU-facing layout. The concrete matrix types keep their own hand-written definitions and
+/// convert to and from `Matrix<T, C, R>` via `From`/`Into`.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Matrix<T, const C: usize, const R: usize> {
+    pub inner: [Vector<T, R>; C],
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable, const C: usize, const R: usize> bytemuck::Zeroable for Matrix<T, C, R> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod, const C: usize, const R: usize> bytemuck::Pod for Matrix<T, C, R> {}
+
+impl<T, const C: usize, const R: usize> Matrix<T, C, R> {
+    #[inline(always)]
+    pub fn new(inner: [Vector<T, R>; C]) -> Self {
+        Self { inner }
+    }
+}
+
+// Same reasoning as `Vector`'s manual `Default` impl above: `[Vector<T, R>; C]` has no blanket
+// `Default` over arbitrary `C`, so build it element-by-element instead of deriving.
+impl<T: Default, const C: usize, const R: usize> Default for Matrix<T, C, R> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(core::array::from_fn(|_| Vector::default()))
+    }
+}
+
+impl<T, const C: usize, const R: usize> From<[Vector<T, R>; C]> for Matrix<T, C, R> {
+    #[inline(always)]
+    fn from(inner: [Vector<T, R>; C]) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T, const C: usize, const R: usize> From<Matrix<T, C, R>> for [Vector<T, R>; C] {
+    #[inline(always)]
+    fn from(other: Matrix<T, C, R>) -> Self {
+        other.inner
+    }
+}
+
 define_matrices! {
     (Mat2x2, ColumnMatrix2, f32, Vec2, 2 * 2, align: 8, size: 16, pad: 0, [0, 1]),
     (Mat2x3, ColumnMatrix2x3, f32, Vec2, 2 * 3, align: 8, size: 32, pad: 8, [0, 1, 2]),
@@ -333,10 +502,68 @@ pub type DMat3 = DMat3x3;
 /// Matrix of f64s with 4 columns and 4 rows. Alignment 32, size 128.
 pub type DMat4 = DMat4x4;
 
+/// `glam` conversions, mirroring the `mint` support above for users who do their actual math in
+/// `glam` instead. Covers the f32 vectors and square matrices; `glam` has no `f64`/integer
+/// vector/matrix types to bridge to [`DVec2`]/[`UVec2`]/[`IVec2`] and friends.
+#[cfg(feature = "glam")]
+mod glam_impls {
+    use super::{Mat2, Mat3, Mat4, Vec2, Vec3, Vec4};
+
+    macro_rules! impl_glam_vector {
+        ($name:ident, $glam_ty:ty) => {
+            impl From<$glam_ty> for $name {
+                #[inline(always)]
+                fn from(other: $glam_ty) -> Self {
+                    Self::new(other.to_array())
+                }
+            }
+
+            impl From<$name> for $glam_ty {
+                #[inline(always)]
+                fn from(other: $name) -> Self {
+                    <$glam_ty>::from_array(other.inner)
+                }
+            }
+        };
+    }
+
+    impl_glam_vector!(Vec2, glam::Vec2);
+    impl_glam_vector!(Vec3, glam::Vec3);
+    impl_glam_vector!(Vec4, glam::Vec4);
+
+    macro_rules! impl_glam_matrix {
+        ($name:ident, $glam_ty:ty, $count:literal) => {
+            impl From<$glam_ty> for $name {
+                #[inline(always)]
+                fn from(other: $glam_ty) -> Self {
+                    Self::from(other.to_cols_array_2d())
+                }
+            }
+
+            impl From<$name> for $glam_ty {
+                #[inline(always)]
+                fn from(other: $name) -> Self {
+                    let cols: [[f32; $count]; $count] = other.into();
+                    <$glam_ty>::from_cols_array_2d(&cols)
+                }
+            }
+        };
+    }
+
+    impl_glam_matrix!(Mat2, glam::Mat2, 2);
+    impl_glam_matrix!(Mat3, glam::Mat3, 3);
+    impl_glam_matrix!(Mat4, glam::Mat4, 4);
+}
+
 /// Pads an element to be in an array in a shader.
 ///
 /// All elements in arrays need to be aligned to 16 bytes. This automatically aligns your types to 16 bytes.
 ///
+/// Conceptually this is [`padding::Padded<T, N>`](padding::Padded) with `N` picked automatically
+/// to round `T` up to 16 bytes, but that `N` depends on `size_of::<T>()`, which can't be
+/// expressed as a const generic default on stable Rust. If you need some other padding amount,
+/// reach for `Padded` directly.
+///
 /// This glsl:
 ///
 /// ```glsl
@@ -355,6 +582,7 @@ pub type DMat4 = DMat4x4;
 /// ```
 #[repr(C, align(16))]
 #[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArrayMember<T>(pub T);
 
 #[cfg(feature = "bytemuck")]
@@ -366,6 +594,9 @@ unsafe impl<T: bytemuck::Pod> bytemuck::Pod for ArrayMember<T> {}
 ///
 /// All dynamic offsets need to be aligned to 256 bytes. This automatically aligns your types to 256s.
 ///
+/// Like [`ArrayMember`], this is conceptually [`padding::Padded<T, N>`](padding::Padded) with `N`
+/// picked automatically to round `T` up to 256 bytes.
+///
 /// Given a shader of:
 ///
 /// ```glsl
@@ -427,6 +658,7 @@ unsafe impl<T: bytemuck::Pod> bytemuck::Pod for ArrayMember<T> {}
 /// ```
 #[repr(C, align(256))]
 #[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DynamicOffsetMember<T>(pub T);
 
 #[cfg(feature = "bytemuck")]
@@ -436,13 +668,162 @@ unsafe impl<T: bytemuck::Pod> bytemuck::Pod for DynamicOffsetMember<T> {}
 
 /// Correctly sized padding helpers.
 pub mod padding {
-    macro_rules! define_padding {
-        ($name:ident, $count:literal <- $doc:literal) => {
+    use core::ops::{Deref, DerefMut};
+
+    /// A value of `T` followed by `N` trailing padding bytes.
+    ///
+    /// This is the one primitive the rest of this module (and [`crate::ArrayMember`] /
+    /// [`crate::DynamicOffsetMember`], conceptually) builds on: ask for exactly the padding you
+    /// need instead of picking from a fixed menu of sizes. `Padded<T, N>` derefs to `T`, so it can
+    /// usually be used as if it were `T` directly.
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+    pub struct Padded<T, const N: usize> {
+        pub inner: T,
+        _padding: [u8; N],
+    }
+
+    #[cfg(feature = "bytemuck")]
+    unsafe impl<T: bytemuck::Zeroable, const N: usize> bytemuck::Zeroable for Padded<T, N> {}
+    #[cfg(feature = "bytemuck")]
+    unsafe impl<T: bytemuck::Pod, const N: usize> bytemuck::Pod for Padded<T, N> {}
+
+    impl<T, const N: usize> Padded<T, N> {
+        #[inline(always)]
+        pub fn new(inner: T) -> Self {
+            Self { inner, _padding: [0; N] }
+        }
+    }
+
+    impl<T: Default, const N: usize> Default for Padded<T, N> {
+        #[inline(always)]
+        fn default() -> Self {
+            Self::new(T::default())
+        }
+    }
+
+    impl<T, const N: usize> From<T> for Padded<T, N> {
+        #[inline(always)]
+        fn from(inner: T) -> Self {
+            Self::new(inner)
+        }
+    }
+
+    impl<T, const N: usize> Deref for Padded<T, N> {
+        type Target = T;
+
+        #[inline(always)]
+        fn deref(&self) -> &T {
+            &self.inner
+        }
+    }
+
+    impl<T, const N: usize> DerefMut for Padded<T, N> {
+        #[inline(always)]
+        fn deref_mut(&mut self) -> &mut T {
+            &mut self.inner
+        }
+    }
+
+    // The fixed-size padding types used to be their own `struct $name { _padding: [u8; N] }` with
+    // a zero-argument `fn new() -> Self`. A plain `type $name = Padded<(), N>` alias can't keep
+    // that constructor: `Padded::new` takes the wrapped value as an argument, and there's no way
+    // to give a *generic* `Padded<T, N>` a second, zero-argument `new` just for `T = ()` without
+    // the two inherent impls conflicting. So these stay thin newtypes around `Padded<(), N>`
+    // instead of bare aliases, deref'ing to it to keep the rest of the composable API.
+    macro_rules! define_padding_alias {
+        ($name:ident, $n:literal, $doc:expr) => {
             #[doc = $doc]
-            #[repr(C)]
+            #[repr(transparent)]
             #[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd)]
+            pub struct $name(Padded<(), $n>);
+
+            #[cfg(feature = "bytemuck")]
+            unsafe impl bytemuck::Zeroable for $name {}
+            #[cfg(feature = "bytemuck")]
+            unsafe impl bytemuck::Pod for $name {}
+
+            impl $name {
+                #[inline(always)]
+                pub fn new() -> Self {
+                    Self(Padded::new(()))
+                }
+            }
+
+            impl Deref for $name {
+                type Target = Padded<(), $n>;
+
+                #[inline(always)]
+                fn deref(&self) -> &Self::Target {
+                    &self.0
+                }
+            }
+
+            impl DerefMut for $name {
+                #[inline(always)]
+                fn deref_mut(&mut self) -> &mut Self::Target {
+                    &mut self.0
+                }
+            }
+        };
+    }
+
+    define_padding_alias!(Pad1Float, 4, "Padding the size of a single float/uint/int. 4 bytes.");
+    define_padding_alias!(Pad2Float, 8, "Padding the size of two floats/uints/ints. 8 bytes.");
+    define_padding_alias!(Pad3Float, 12, "Padding the size of three floats/uints/ints. 12 bytes.");
+    define_padding_alias!(Pad4Float, 16, "Padding the size of four floats/uints/ints. 16 bytes.");
+    define_padding_alias!(Pad1Double, 8, "Padding the size of a single double. 8 bytes.");
+    define_padding_alias!(Pad2Double, 16, "Padding the size of two doubles. 16 bytes.");
+    define_padding_alias!(Pad3Double, 24, "Padding the size of three doubles. 24 bytes.");
+    define_padding_alias!(Pad4Double, 32, "Padding the size of four doubles. 32 bytes.");
+}
+
+/// std430-layout matrix and array helpers, for use in storage buffers and push constants.
+///
+/// Unlike std140 (the default layout used by the rest of this crate), std430 drops the rule
+/// that rounds array strides and struct alignment up to 16 bytes: an array or struct takes the
+/// alignment of its largest member instead of being rounded up to a `vec4`. `vec3` still aligns
+/// to 16 bytes (`dvec3` to 32) and matrices are still laid out as arrays of column vectors in both
+/// layouts, so the vector types at the crate root are reused here unchanged; only the matrices
+/// and the array padding helper differ.
+pub mod std430 {
+    use super::{DVec2, DVec3, DVec4, Vec2, Vec3, Vec4};
+
+    macro_rules! define_std430_matrices {
+        ( $(( $name:ident, $mint_name:ident, $prim_ty:ty, $row_ty:ty, $rows:literal * $cols:literal, align: $align:literal, [$($idx:literal),*] ),)* ) => {
+            $(
+                define_std430_matrices!(@impl
+                    $name,
+                    mint::$mint_name<$prim_ty>,
+                    $align,
+                    $prim_ty,
+                    $row_ty,
+                    $rows,
+                    $cols,
+                    [$( $idx ),*],
+                    concat!(
+                        "std430 matrix of `", stringify!($prim_ty), "` values with ", stringify!($rows), " rows and ", stringify!($cols), " columns. ",
+                        "Has alignment ", stringify!($align), "."
+                    ),
+                    concat!(
+                        "Construct a `", stringify!($name), "` from any type which is convertable into a ",
+                        "`mint::", stringify!($mint_name), "<", stringify!($prim_ty), ">`."
+                    )
+                );
+            )*
+        };
+
+        (@impl $name:ident, $mint_type:ty, $align:literal, $inner_ty:ty, $ty:ty, $count_x:literal, $count_y:literal, [$( $idx:literal ),*], $doc:expr, $mint_doc:expr) => {
+            #[doc = $doc]
+            #[repr(C, align($align))]
+            #[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd)]
+            // `transparent` keeps this a bare array on the wire, matching the std140 vector and
+            // matrix types at the crate root (whose vectors this module reuses) instead of a
+            // one-off `{"inner": [...]}` shape.
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+            #[cfg_attr(feature = "serde", serde(transparent))]
             pub struct $name {
-                _padding: [u8; $count],
+                pub inner: [$ty; $count_y],
             }
 
             #[cfg(feature = "bytemuck")]
@@ -452,19 +833,366 @@ pub mod padding {
 
             impl $name {
                 #[inline(always)]
-                pub fn new() -> Self {
-                    Self::default()
+                pub fn new(inner: [$ty; $count_y]) -> Self {
+                    Self { inner }
                 }
+
+                #[cfg(feature = "mint")]
+                #[doc = $mint_doc]
+                #[inline(always)]
+                pub fn from_mint<T: Into<$mint_type>>(value: T) -> Self {
+                    Self::from(value.into())
+                }
+            }
+
+            #[cfg(feature = "mint")]
+            impl From<$mint_type> for $name {
+                #[inline(always)]
+                fn from(other: $mint_type) -> Self {
+                    // Mint's types do not implement From for arrays, only Into.
+                    let as_arr: [$inner_ty; $count_x * $count_y] = other.into();
+                    as_arr.into()
+                }
+            }
+
+            impl From<[$ty; $count_y]> for $name {
+                #[inline(always)]
+                fn from(inner: [$ty; $count_y]) -> Self {
+                    Self { inner }
+                }
+            }
+
+            impl From<[$inner_ty; $count_x * $count_y]> for $name {
+                #[inline(always)]
+                fn from(inner: [$inner_ty; $count_x * $count_y]) -> Self {
+                    let d2: [[$inner_ty; $count_x]; $count_y] = unsafe { core::mem::transmute(inner) };
+                    Self {
+                        inner: [$(<$ty>::from(d2[$idx])),*],
+                    }
+                }
+            }
+
+            impl From<[[$inner_ty; $count_x]; $count_y]> for $name {
+                #[inline(always)]
+                fn from(inner: [[$inner_ty; $count_x]; $count_y]) -> Self {
+                    Self {
+                        inner: [$(<$ty>::from(inner[$idx])),*],
+                    }
+                }
+            }
+
+            #[cfg(feature = "mint")]
+            impl From<$name> for $mint_type {
+                #[inline(always)]
+                fn from(other: $name) -> Self {
+                    let as_arr = <[[$inner_ty; $count_x]; $count_y]>::from(other);
+                    as_arr.into()
+                }
+            }
+
+            impl From<$name> for [$ty; $count_y] {
+                #[inline(always)]
+                fn from(other: $name) -> Self {
+                    other.inner
+                }
+            }
+
+            impl From<$name> for [$inner_ty; $count_x * $count_y] {
+                #[inline(always)]
+                fn from(other: $name) -> Self {
+                    let d2: [[$inner_ty; $count_x]; $count_y] = [$(<[$inner_ty; $count_x]>::from(other.inner[$idx])),*];
+                    unsafe { core::mem::transmute(d2) }
+                }
+            }
+
+            impl From<$name> for [[$inner_ty; $count_x]; $count_y] {
+                #[inline(always)]
+                fn from(other: $name) -> Self {
+                    [$(<[$inner_ty; $count_x]>::from(other.inner[$idx])),*]
+                }
+            }
+        };
+    }
+
+    define_std430_matrices! {
+        (Mat2x2, ColumnMatrix2, f32, Vec2, 2 * 2, align: 8, [0, 1]),
+        (Mat2x3, ColumnMatrix2x3, f32, Vec2, 2 * 3, align: 8, [0, 1, 2]),
+        (Mat2x4, ColumnMatrix2x4, f32, Vec2, 2 * 4, align: 8, [0, 1, 2, 3]),
+
+        (Mat3x2, ColumnMatrix3x2, f32, Vec3, 3 * 2, align: 16, [0, 1]),
+        (Mat3x3, ColumnMatrix3, f32, Vec3, 3 * 3, align: 16, [0, 1, 2]),
+        (Mat3x4, ColumnMatrix3x4, f32, Vec3, 3 * 4, align: 16, [0, 1, 2, 3]),
+
+        (Mat4x2, ColumnMatrix4x2, f32, Vec4, 4 * 2, align: 16, [0, 1]),
+        (Mat4x3, ColumnMatrix4x3, f32, Vec4, 4 * 3, align: 16, [0, 1, 2]),
+        (Mat4x4, ColumnMatrix4, f32, Vec4, 4 * 4, align: 16, [0, 1, 2, 3]),
+
+        (DMat2x2, ColumnMatrix2, f64, DVec2, 2 * 2, align: 16, [0, 1]),
+        (DMat2x3, ColumnMatrix2x3, f64, DVec2, 2 * 3, align: 16, [0, 1, 2]),
+        (DMat2x4, ColumnMatrix2x4, f64, DVec2, 2 * 4, align: 16, [0, 1, 2, 3]),
+
+        (DMat3x2, ColumnMatrix3x2, f64, DVec3, 3 * 2, align: 32, [0, 1]),
+        (DMat3x3, ColumnMatrix3, f64, DVec3, 3 * 3, align: 32, [0, 1, 2]),
+        (DMat3x4, ColumnMatrix3x4, f64, DVec3, 3 * 4, align: 32, [0, 1, 2, 3]),
+
+        (DMat4x2, ColumnMatrix4x2, f64, DVec4, 4 * 2, align: 32, [0, 1]),
+        (DMat4x3, ColumnMatrix4x3, f64, DVec4, 4 * 3, align: 32, [0, 1, 2]),
+        (DMat4x4, ColumnMatrix4, f64, DVec4, 4 * 4, align: 32, [0, 1, 2, 3]),
+    }
+
+    /// std430 matrix of f32s with 2 columns and 2 rows. Alignment 8.
+    pub type Mat2 = Mat2x2;
+    /// std430 matrix of f32s with 3 columns and 3 rows. Alignment 16.
+    pub type Mat3 = Mat3x3;
+    /// std430 matrix of f32s with 4 columns and 4 rows. Alignment 16.
+    pub type Mat4 = Mat4x4;
+    /// std430 matrix of f64s with 2 columns and 2 rows. Alignment 16.
+    pub type DMat2 = DMat2x2;
+    /// std430 matrix of f64s with 3 columns and 3 rows. Alignment 32.
+    pub type DMat3 = DMat3x3;
+    /// std430 matrix of f64s with 4 columns and 4 rows. Alignment 32.
+    pub type DMat4 = DMat4x4;
+
+    /// Pads an element to be in a std430 array.
+    ///
+    /// Unlike [`crate::ArrayMember`], std430 array elements are aligned to the alignment of the
+    /// element itself rather than rounded up to 16 bytes, so this is a transparent wrapper with
+    /// no extra padding: `Std430ArrayMember<f32>` has a stride of 4, and
+    /// `Std430ArrayMember<Vec2>` has a stride of 8.
+    ///
+    /// This glsl:
+    ///
+    /// ```glsl
+    /// buffer FloatArray {
+    ///     float array[];
+    /// };
+    /// ```
+    ///
+    /// turns into:
+    ///
+    /// ```rust
+    /// #[repr(C)]
+    /// struct FloatArray {
+    ///     array: [shader_types::std430::Std430ArrayMember<f32>]
+    /// }
+    /// ```
+    #[repr(transparent)]
+    #[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd, Eq, Ord, Hash)]
+    pub struct Std430ArrayMember<T>(pub T);
+
+    #[cfg(feature = "bytemuck")]
+    unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for Std430ArrayMember<T> {}
+    #[cfg(feature = "bytemuck")]
+    unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Std430ArrayMember<T> {}
+}
+
+/// Derives a std140-padded mirror of a struct made up of this crate's vector, matrix, and array
+/// types, inserting `_padN: [u8; N]` fields so member-to-member padding doesn't have to be minded
+/// by hand. See the `derive` feature and [`Std140Element`].
+#[cfg(feature = "derive")]
+pub use shader_types_derive::Std140;
+
+/// A crate type with a known std140 alignment and size.
+///
+/// Implemented for every std140 vector and matrix type at the crate root, [`ArrayMember`],
+/// [`DynamicOffsetMember`], arrays of these, and the primitive types valid as members (`f32`,
+/// `f64`, `i32`, `u32`). The [`padding`] helpers, the [`std430`] types and the generic
+/// [`Vector`]/[`Matrix`] do not implement it. This is sealed: it exists so the [`Std140`] derive has a single source of truth for the sizes
+/// baked into the macros above, not so downstream crates can describe their own layouts with it.
+#[cfg(feature = "derive")]
+pub trait Std140Element: sealed::Sealed {
+    /// The std140 alignment of this type, in bytes.
+    const ALIGNMENT: usize;
+    /// The in-memory size of this type (`size_of`), in bytes, including any tail padding its
+    /// alignment adds (so `Vec3` is 16, not the 12 bytes of its data).
+    const SIZE: usize;
+}
+
+#[cfg(feature = "derive")]
+mod sealed {
+    pub trait Sealed {}
+}
+
+// `ALIGNMENT`/`SIZE` are derived from `align_of`/`size_of` of the real type rather than
+// hand-maintained literals. The derive inlines these types directly into its generated mirror
+// struct, so what matters for its offset arithmetic is the type's *actual* in-memory footprint,
+// including any tail padding the compiler inserts to satisfy `#[repr(align(N))]` (e.g. `Vec3` is
+// 12 bytes of data but `size_of::<Vec3>()` is 16, because of its `align(16)`) -- not the
+// "logical" GLSL size, which would undercount that and throw off every field after it.
+#[cfg(feature = "derive")]
+macro_rules! impl_std140_element {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $ty {}
+            impl Std140Element for $ty {
+                const ALIGNMENT: usize = core::mem::align_of::<$ty>();
+                const SIZE: usize = core::mem::size_of::<$ty>();
             }
+        )*
+    };
+}
+
+#[cfg(feature = "derive")]
+impl_std140_element! {
+    f32, f64, i32, u32,
+
+    Vec2, Vec3, Vec4,
+    DVec2, DVec3, DVec4,
+    UVec2, UVec3, UVec4,
+    IVec2, IVec3, IVec4,
+
+    Mat2x2, Mat2x3, Mat2x4,
+    Mat3x2, Mat3x3, Mat3x4,
+    Mat4x2, Mat4x3, Mat4x4,
+    DMat2x2, DMat2x3, DMat2x4,
+    DMat3x2, DMat3x3, DMat3x4,
+    DMat4x2, DMat4x3, DMat4x4,
+}
+
+#[cfg(feature = "derive")]
+impl<T: Std140Element> sealed::Sealed for ArrayMember<T> {}
+#[cfg(feature = "derive")]
+impl<T: Std140Element> Std140Element for ArrayMember<T> {
+    const ALIGNMENT: usize = core::mem::align_of::<Self>();
+    const SIZE: usize = core::mem::size_of::<Self>();
+}
+
+#[cfg(feature = "derive")]
+impl<T: Std140Element> sealed::Sealed for DynamicOffsetMember<T> {}
+#[cfg(feature = "derive")]
+impl<T: Std140Element> Std140Element for DynamicOffsetMember<T> {
+    const ALIGNMENT: usize = core::mem::align_of::<Self>();
+    const SIZE: usize = core::mem::size_of::<Self>();
+}
+
+/// Plain Rust arrays of a [`Std140Element`] type, e.g. `[ArrayMember<i32>; 3]`, so `#[derive(
+/// Std140)]` can be used on struct fields declared as arrays directly (as in the crate's own
+/// top-level example) instead of requiring every array to be wrapped in a named type.
+///
+/// Elements are assumed to already carry their own std140 array stride as their `SIZE` (as
+/// [`ArrayMember`] does), so the array itself just takes the element's alignment and repeats its
+/// size `N` times back-to-back.
+#[cfg(feature = "derive")]
+impl<T: Std140Element, const N: usize> sealed::Sealed for [T; N] {}
+#[cfg(feature = "derive")]
+impl<T: Std140Element, const N: usize> Std140Element for [T; N] {
+    const ALIGNMENT: usize = T::ALIGNMENT;
+    const SIZE: usize = T::SIZE * N;
+}
+
+/// A crate type with a known GLSL spelling.
+///
+/// Implemented for every std140 vector and matrix type at the crate root, [`ArrayMember`],
+/// [`DynamicOffsetMember`], and the primitive types valid as members; the [`std430`] types and the
+/// generic [`Vector`]/[`Matrix`] do not implement it. The [`Std140`] derive uses this to build each block's
+/// `GLSL_BLOCK` constant, which can be diffed against actual shader source (or asserted on in
+/// tests) to catch Rust-side/shader-side layout drift without a full reflection system.
+#[cfg(feature = "derive")]
+pub trait GlslType {
+    /// The GLSL spelling of this type, e.g. `"vec3"` or `"mat4"`.
+    const GLSL_TYPE: &'static str;
+}
+
+#[cfg(feature = "derive")]
+macro_rules! impl_glsl_type {
+    ($({ $ty:ty, $glsl:literal }),* $(,)?) => {
+        $(
+            impl GlslType for $ty {
+                const GLSL_TYPE: &'static str = $glsl;
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "derive")]
+impl_glsl_type! {
+    { f32, "float" },
+    { f64, "double" },
+    { i32, "int" },
+    { u32, "uint" },
+
+    { Vec2, "vec2" },
+    { Vec3, "vec3" },
+    { Vec4, "vec4" },
+    { DVec2, "dvec2" },
+    { DVec3, "dvec3" },
+    { DVec4, "dvec4" },
+    { UVec2, "uvec2" },
+    { UVec3, "uvec3" },
+    { UVec4, "uvec4" },
+    { IVec2, "ivec2" },
+    { IVec3, "ivec3" },
+    { IVec4, "ivec4" },
+
+    { Mat2x2, "mat2" },
+    { Mat2x3, "mat3x2" },
+    { Mat2x4, "mat4x2" },
+    { Mat3x2, "mat2x3" },
+    { Mat3x3, "mat3" },
+    { Mat3x4, "mat4x3" },
+    { Mat4x2, "mat2x4" },
+    { Mat4x3, "mat3x4" },
+    { Mat4x4, "mat4" },
+    { DMat2x2, "dmat2" },
+    { DMat2x3, "dmat3x2" },
+    { DMat2x4, "dmat4x2" },
+    { DMat3x2, "dmat2x3" },
+    { DMat3x3, "dmat3" },
+    { DMat3x4, "dmat4x3" },
+    { DMat4x2, "dmat2x4" },
+    { DMat4x3, "dmat3x4" },
+    { DMat4x4, "dmat4" },
+}
+
+#[cfg(feature = "derive")]
+impl<T: GlslType> GlslType for ArrayMember<T> {
+    const GLSL_TYPE: &'static str = T::GLSL_TYPE;
+}
+
+#[cfg(feature = "derive")]
+impl<T: GlslType> GlslType for DynamicOffsetMember<T> {
+    const GLSL_TYPE: &'static str = T::GLSL_TYPE;
+}
+
+/// Re-exported so the [`Std140`] derive's generated `GLSL_BLOCK` constants can reach
+/// `concatcp!` without requiring it as a direct dependency too.
+#[cfg(feature = "derive")]
+pub use const_format;
+
+#[cfg(all(test, feature = "derive"))]
+mod std140_derive_tests {
+    use super::*;
+
+    // Offsets are read back via raw pointers rather than constructed instances, since the
+    // mirror struct's `_padN` fields are `#[doc(hidden)]` and not meant to be built by hand.
+    macro_rules! field_offset {
+        ($ptr:expr, $field:ident) => {
+            unsafe { (core::ptr::addr_of!((*$ptr).$field) as *const u8 as usize) - ($ptr as *const u8 as usize) }
         };
     }
 
-    define_padding!(Pad1Float, 4 <- "Padding the size of a single float/uint/int. 4 bytes.");
-    define_padding!(Pad2Float, 8 <- "Padding the size of two floats/uints/ints. 8 bytes.");
-    define_padding!(Pad3Float, 12 <- "Padding the size of three floats/uints/ints. 12 bytes.");
-    define_padding!(Pad4Float, 16 <- "Padding the size of four floats/uints/ints. 16 bytes.");
-    define_padding!(Pad1Double, 8 <- "Padding the size of a single double. 8 bytes.");
-    define_padding!(Pad2Double, 16 <- "Padding the size of two doubles. 16 bytes.");
-    define_padding!(Pad3Double, 24 <- "Padding the size of three doubles. 24 bytes.");
-    define_padding!(Pad4Double, 32 <- "Padding the size of four doubles. 32 bytes.");
+    // The exact shape from the crate's top-level doctest: `mvp: Mat4, position: Vec3, normal:
+    // Vec3, uv: Vec2` is the most common uniform-block shape (two consecutive `vec3`s), and is
+    // the case that was silently mislaid out before `Std140Element::SIZE` matched `size_of`.
+    #[derive(Std140)]
+    struct UniformBlock {
+        mvp: Mat4,
+        position: Vec3,
+        normal: Vec3,
+        uv: Vec2,
+        constants: [ArrayMember<i32>; 3],
+    }
+
+    #[test]
+    fn derived_offsets_match_std140() {
+        let uninit = core::mem::MaybeUninit::<UniformBlockStd140>::uninit();
+        let ptr = uninit.as_ptr();
+
+        assert_eq!(field_offset!(ptr, mvp), 0);
+        assert_eq!(field_offset!(ptr, position), 64);
+        assert_eq!(field_offset!(ptr, normal), 80);
+        assert_eq!(field_offset!(ptr, uv), 96);
+        assert_eq!(field_offset!(ptr, constants), 112);
+        assert_eq!(core::mem::size_of::<UniformBlockStd140>(), 160);
+    }
 }